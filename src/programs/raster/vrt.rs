@@ -1,22 +1,68 @@
 use crate::{
     errors::*,
+    raster::ResampleAlg,
     utils::{_last_null_pointer_err, _path_to_c_string},
     Dataset,
 };
 use gdal_sys::GDALBuildVRTOptions;
-use libc::{c_char, c_int};
+use libc::{c_char, c_int, c_void};
 use std::{
     borrow::Borrow,
-    ffi::CString,
+    ffi::{CStr, CString},
     path::Path,
     ptr::{null, null_mut},
 };
 
+/// State shared between a [`BuildVRTOptions`] and the `GDALProgressFunc` trampoline registered
+/// for it. Kept as a free-standing helper so the panic-catching and cancellation bookkeeping can
+/// later be reused by the Warp/Translate/Rasterize `gdal_utils` wrappers.
+struct ProgressState {
+    callback: Box<dyn FnMut(f64, &str) -> bool>,
+    cancelled: bool,
+}
+
+/// `GDALProgressFunc` trampoline that recovers a [`ProgressState`] from `p_progress_arg` and
+/// forwards into the boxed Rust closure.
+///
+/// A panicking callback is caught here rather than allowed to unwind across the FFI boundary;
+/// the operation is reported to GDAL as cancelled in that case.
+///
+/// # Safety
+/// `p_progress_arg` must point to a live `ProgressState` for the duration of the call.
+unsafe extern "C" fn progress_trampoline(
+    complete: f64,
+    message: *const c_char,
+    p_progress_arg: *mut c_void,
+) -> c_int {
+    let state = &mut *(p_progress_arg as *mut ProgressState);
+
+    let message = if message.is_null() {
+        ""
+    } else {
+        CStr::from_ptr(message).to_str().unwrap_or("")
+    };
+
+    let proceed = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        (state.callback)(complete, message)
+    }))
+    .unwrap_or(false);
+
+    if proceed {
+        1
+    } else {
+        state.cancelled = true;
+        0
+    }
+}
+
 /// Wraps a [GDALBuildVRTOptions] object.
 ///
 /// [GDALBuildVRTOptions]: https://gdal.org/api/gdal_utils.html#_CPPv419GDALBuildVRTOptions
 pub struct BuildVRTOptions {
     c_options: *mut GDALBuildVRTOptions,
+    // Kept alive for as long as `c_options` may invoke it; boxing means its heap address (which
+    // GDAL holds onto as the progress callback's user data) stays stable even if `self` moves.
+    progress: Option<Box<ProgressState>>,
 }
 
 impl BuildVRTOptions {
@@ -42,10 +88,39 @@ impl BuildVRTOptions {
         unsafe {
             Ok(Self {
                 c_options: gdal_sys::GDALBuildVRTOptionsNew(c_args.as_mut_ptr(), null_mut()),
+                progress: None,
             })
         }
     }
 
+    /// Registers a progress callback invoked periodically while [`build_vrt`] or
+    /// [`build_vrt_from_paths`] executes.
+    ///
+    /// The callback receives the fraction complete (`0.0..=1.0`) and a status message, and
+    /// returns `true` to continue or `false` to cancel the operation. A cancelled build
+    /// surfaces as an error from `build_vrt`/`build_vrt_from_paths`.
+    pub fn set_progress<F: FnMut(f64, &str) -> bool + 'static>(&mut self, callback: F) {
+        let mut state = Box::new(ProgressState {
+            callback: Box::new(callback),
+            cancelled: false,
+        });
+
+        unsafe {
+            gdal_sys::GDALBuildVRTOptionsSetProgress(
+                self.c_options,
+                Some(progress_trampoline),
+                state.as_mut() as *mut ProgressState as *mut c_void,
+            );
+        }
+
+        self.progress = Some(state);
+    }
+
+    /// Returns `true` if a registered progress callback requested cancellation.
+    fn progress_cancelled(&self) -> bool {
+        self.progress.as_deref().is_some_and(|s| s.cancelled)
+    }
+
     /// Returns the wrapped C pointer
     ///
     /// # Safety
@@ -63,6 +138,235 @@ impl Drop for BuildVRTOptions {
     }
 }
 
+/// Resolution strategy for [`BuildVRTOptionsBuilder::resolution`].
+///
+/// Corresponds to the `-resolution` flag accepted by `gdalbuildvrt`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ResolutionStrategy {
+    /// Use the highest resolution among the input datasets.
+    Highest,
+    /// Use the lowest resolution among the input datasets.
+    Lowest,
+    /// Use the average resolution among the input datasets.
+    Average,
+    /// Use an explicit, user-defined pixel size.
+    UserDefined { xres: f64, yres: f64 },
+}
+
+impl ResolutionStrategy {
+    /// Render the argv tokens (including the `-resolution` flag and, for
+    /// [`ResolutionStrategy::UserDefined`], the accompanying `-tr` flag) for this strategy.
+    fn to_args(self) -> Vec<String> {
+        match self {
+            Self::Highest => vec!["-resolution".to_string(), "highest".to_string()],
+            Self::Lowest => vec!["-resolution".to_string(), "lowest".to_string()],
+            Self::Average => vec!["-resolution".to_string(), "average".to_string()],
+            Self::UserDefined { xres, yres } => vec![
+                "-resolution".to_string(),
+                "user".to_string(),
+                "-tr".to_string(),
+                xres.to_string(),
+                yres.to_string(),
+            ],
+        }
+    }
+}
+
+/// Renders a [`ResampleAlg`] as the resampling method name accepted by `gdalbuildvrt`'s `-r` flag.
+fn resampling_arg(alg: ResampleAlg) -> &'static str {
+    match alg {
+        ResampleAlg::NearestNeighbour => "nearest",
+        ResampleAlg::Bilinear => "bilinear",
+        ResampleAlg::Cubic => "cubic",
+        ResampleAlg::CubicSpline => "cubicspline",
+        ResampleAlg::Lanczos => "lanczos",
+        ResampleAlg::Average => "average",
+        ResampleAlg::Mode => "mode",
+        ResampleAlg::Gauss => "gauss",
+    }
+}
+
+/// Builds a list of argv tokens for a space-separated numeric list flag (e.g. `-srcnodata`).
+fn nodata_arg(flag: &str, values: &[f64]) -> Vec<String> {
+    vec![
+        flag.to_string(),
+        values
+            .iter()
+            .map(f64::to_string)
+            .collect::<Vec<_>>()
+            .join(" "),
+    ]
+}
+
+/// A strongly-typed builder for [`BuildVRTOptions`].
+///
+/// Unlike [`BuildVRTOptions::new`], which takes raw CLI-style argv tokens, this builder exposes
+/// typed setters for the options most commonly used when mosaicking datasets, so mistakes like
+/// a mistyped flag or out-of-range resampling name are caught at compile time instead of inside
+/// `GDALBuildVRTOptionsNew`.
+///
+/// # Example
+/// ```rust, no_run
+/// use gdal::programs::raster::{BuildVRTOptionsBuilder, ResolutionStrategy};
+/// use gdal::raster::ResampleAlg;
+///
+/// # fn main() -> gdal::errors::Result<()> {
+/// let options = BuildVRTOptionsBuilder::new()
+///     .resolution(ResolutionStrategy::Highest)
+///     .resampling(ResampleAlg::Bilinear)
+///     .add_alpha(true)
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+// Not `Debug`/`Clone`: the optional progress callback is a boxed `FnMut` and can't implement
+// either.
+#[derive(Default)]
+pub struct BuildVRTOptionsBuilder {
+    resolution: Option<ResolutionStrategy>,
+    resampling: Option<ResampleAlg>,
+    bands: Vec<usize>,
+    separate: Option<bool>,
+    add_alpha: Option<bool>,
+    src_nodata: Vec<f64>,
+    vrt_nodata: Vec<f64>,
+    target_extent: Option<(f64, f64, f64, f64)>,
+    allow_projection_difference: Option<bool>,
+    progress: Option<Box<dyn FnMut(f64, &str) -> bool>>,
+}
+
+impl BuildVRTOptionsBuilder {
+    /// Creates a new, empty builder.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Sets the output resolution strategy. See [`ResolutionStrategy`].
+    pub fn resolution(mut self, resolution: ResolutionStrategy) -> Self {
+        self.resolution = Some(resolution);
+        self
+    }
+
+    /// Sets the resampling algorithm used when datasets have different resolutions.
+    pub fn resampling(mut self, resampling: ResampleAlg) -> Self {
+        self.resampling = Some(resampling);
+        self
+    }
+
+    /// Selects a subset of input bands to place in the VRT, in the given order.
+    ///
+    /// Band numbers are 1-based, matching the GDAL convention.
+    pub fn bands(mut self, bands: &[usize]) -> Self {
+        self.bands = bands.to_vec();
+        self
+    }
+
+    /// If `true`, places each input dataset into a separate band of the VRT, rather than
+    /// stacking datasets that share the same band structure.
+    pub fn separate(mut self, state: bool) -> Self {
+        self.separate = Some(state);
+        self
+    }
+
+    /// If `true`, adds an alpha mask band to the VRT.
+    pub fn add_alpha(mut self, state: bool) -> Self {
+        self.add_alpha = Some(state);
+        self
+    }
+
+    /// Sets the nodata value(s) to treat as transparent in the source datasets.
+    pub fn src_nodata(mut self, values: &[f64]) -> Self {
+        self.src_nodata = values.to_vec();
+        self
+    }
+
+    /// Sets the nodata value(s) to expose on the resulting VRT bands.
+    pub fn vrt_nodata(mut self, values: &[f64]) -> Self {
+        self.vrt_nodata = values.to_vec();
+        self
+    }
+
+    /// Restricts the VRT to the given extent, in the order `(xmin, ymin, xmax, ymax)`.
+    pub fn target_extent(mut self, xmin: f64, ymin: f64, xmax: f64, ymax: f64) -> Self {
+        self.target_extent = Some((xmin, ymin, xmax, ymax));
+        self
+    }
+
+    /// If `true`, allows mosaicking datasets with different projections
+    /// (the projection of the first dataset is used for the VRT).
+    pub fn allow_projection_difference(mut self, state: bool) -> Self {
+        self.allow_projection_difference = Some(state);
+        self
+    }
+
+    /// Registers a progress callback, invoked periodically while `GDALBuildVRT` executes with
+    /// the fraction complete and a status message. Returning `false` cancels the operation.
+    ///
+    /// See [`BuildVRTOptions::set_progress`].
+    pub fn progress<F: FnMut(f64, &str) -> bool + 'static>(mut self, callback: F) -> Self {
+        self.progress = Some(Box::new(callback));
+        self
+    }
+
+    /// Renders this builder's options into argv tokens and constructs a [`BuildVRTOptions`].
+    ///
+    /// Consumes the builder: since the progress callback (if any) can only be handed to one
+    /// [`BuildVRTOptions`], this is a one-shot conversion rather than a repeatable render.
+    pub fn build(self) -> Result<BuildVRTOptions> {
+        let mut args = Vec::new();
+
+        if let Some(resolution) = self.resolution {
+            args.extend(resolution.to_args());
+        }
+
+        if let Some(resampling) = self.resampling {
+            args.push("-r".to_string());
+            args.push(resampling_arg(resampling).to_string());
+        }
+
+        for band in &self.bands {
+            args.push("-b".to_string());
+            args.push(band.to_string());
+        }
+
+        if self.separate == Some(true) {
+            args.push("-separate".to_string());
+        }
+
+        if self.add_alpha == Some(true) {
+            args.push("-addalpha".to_string());
+        }
+
+        if !self.src_nodata.is_empty() {
+            args.extend(nodata_arg("-srcnodata", &self.src_nodata));
+        }
+
+        if !self.vrt_nodata.is_empty() {
+            args.extend(nodata_arg("-vrtnodata", &self.vrt_nodata));
+        }
+
+        if let Some((xmin, ymin, xmax, ymax)) = self.target_extent {
+            args.push("-te".to_string());
+            args.push(xmin.to_string());
+            args.push(ymin.to_string());
+            args.push(xmax.to_string());
+            args.push(ymax.to_string());
+        }
+
+        if self.allow_projection_difference == Some(true) {
+            args.push("-allow_projection_difference".to_string());
+        }
+
+        let mut options = BuildVRTOptions::new(args)?;
+
+        if let Some(callback) = self.progress {
+            options.set_progress(callback);
+        }
+
+        Ok(options)
+    }
+}
+
 // helper for distinguishing betweeen invocation modes.
 enum DSSpec<'a> {
     DS(Vec<&'a Dataset>),
@@ -116,6 +420,51 @@ pub fn build_vrt_from_paths<P: AsRef<Path>>(
     )
 }
 
+/// Monotonic counter used to give each [`build_vrt_xml`] call its own `/vsimem/` path on this
+/// process, so concurrent calls don't collide.
+static VRT_XML_MEM_FILE_COUNTER: std::sync::atomic::AtomicU64 =
+    std::sync::atomic::AtomicU64::new(0);
+
+/// Build a VRT from a list of datasets and return the generated VRT XML, without touching disk.
+///
+/// Builds the VRT into GDAL's `/vsimem/` virtual filesystem, reads the resulting XML back out as
+/// a UTF-8 string, and unlinks the backing vsimem entry before returning. This is useful for
+/// pipelines that want to post-process VRT XML (e.g. editing band definitions) before reopening
+/// it, without the churn of a real temp file.
+pub fn build_vrt_xml<D: Borrow<Dataset>>(
+    datasets: &[D],
+    options: Option<BuildVRTOptions>,
+) -> Result<String> {
+    let id = VRT_XML_MEM_FILE_COUNTER.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+    let mem_path = std::path::PathBuf::from(format!(
+        "/vsimem/build_vrt_xml_{}_{id}.vrt",
+        std::process::id()
+    ));
+
+    // The opened `Dataset` isn't needed; only its serialized VRT XML, read back below.
+    drop(build_vrt(Some(&mem_path), datasets, options)?);
+
+    let c_path = _path_to_c_string(&mem_path)?;
+    let bytes = unsafe {
+        let mut length: gdal_sys::vsi_l_offset = 0;
+        // `bUnlinkAndSeize = true` hands ownership of the bytes to us and unlinks the vsimem
+        // entry as part of the same call, so there's no separate cleanup step on success.
+        let data = gdal_sys::VSIGetMemFileBuffer(c_path.as_ptr(), &mut length, true as c_int);
+
+        if data.is_null() {
+            return Err(_last_null_pointer_err("VSIGetMemFileBuffer"));
+        }
+
+        let slice = std::slice::from_raw_parts(data, length as usize);
+        let owned = slice.to_vec();
+        gdal_sys::VSIFree(data as *mut c_void);
+        owned
+    };
+
+    String::from_utf8(bytes)
+        .map_err(|e| GdalError::BadArgument(format!("VRT XML is not valid UTF-8: {e}")))
+}
+
 fn _build_vrt(
     dest: Option<&Path>,
     datasets: &DSSpec,
@@ -162,6 +511,11 @@ fn _build_vrt(
     };
 
     if dataset_out.is_null() {
+        if options.as_ref().is_some_and(BuildVRTOptions::progress_cancelled) {
+            return Err(GdalError::BadArgument(
+                "build_vrt cancelled by progress callback".to_string(),
+            ));
+        }
         return Err(_last_null_pointer_err("GDALBuildVRT"));
     }
 
@@ -176,6 +530,8 @@ mod tests {
     use std::path::Path;
     use crate::{Dataset, errors};
     use crate::programs::raster::build_vrt;
+    use crate::programs::raster::{BuildVRTOptionsBuilder, ResolutionStrategy};
+    use crate::raster::ResampleAlg;
 
     #[test]
     fn vrt_from_ds_and_path() -> errors::Result<()> {
@@ -192,4 +548,119 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn vrt_from_builder_options() -> errors::Result<()> {
+        let infile = Path::new("fixtures/m_3607824_se_17_1_20160620_sub.tif");
+        let ds = Dataset::open(infile)?;
+        let outfile = env::temp_dir().join("test_builder.vrt");
+
+        let options = BuildVRTOptionsBuilder::new()
+            .resolution(ResolutionStrategy::Highest)
+            .resampling(ResampleAlg::Bilinear)
+            .add_alpha(true)
+            .allow_projection_difference(true)
+            .build()?;
+        let vrt = build_vrt(Some(&outfile), &[&ds], Some(options))?;
+        assert_eq!(vrt.raster_count(), ds.raster_count() + 1);
+
+        Ok(())
+    }
+
+    /// `-r`/`resampling_arg` actually reaches `gdalbuildvrt`: mosaic the same dataset onto a
+    /// coarser, explicit resolution (so the VRT must resample, not just pass pixels through) with
+    /// nearest-neighbour and bilinear, and assert the two renders disagree on at least one pixel.
+    /// Asserting only `raster_count`, as the test above does, would pass even if `.resampling(..)`
+    /// were silently ignored.
+    #[test]
+    fn vrt_resampling_option_changes_pixel_values() -> errors::Result<()> {
+        let infile = Path::new("fixtures/m_3607824_se_17_1_20160620_sub.tif");
+        let ds = Dataset::open(infile)?;
+        let geo_transform = ds.geo_transform()?;
+        let coarse_xres = geo_transform[1] * 2.0;
+        let coarse_yres = geo_transform[5].abs() * 2.0;
+
+        let render = |resampling: ResampleAlg, out_name: &str| -> errors::Result<Vec<u8>> {
+            let outfile = env::temp_dir().join(out_name);
+            let options = BuildVRTOptionsBuilder::new()
+                .resolution(ResolutionStrategy::UserDefined {
+                    xres: coarse_xres,
+                    yres: coarse_yres,
+                })
+                .resampling(resampling)
+                .build()?;
+            let vrt = build_vrt(Some(&outfile), &[&ds], Some(options))?;
+            let band = vrt.rasterband(1)?;
+            let buf = band.read_as::<u8>((0, 0), band.size(), band.size(), None)?;
+            Ok(buf.data().to_vec())
+        };
+
+        let nearest = render(ResampleAlg::NearestNeighbour, "test_builder_nearest.vrt")?;
+        let bilinear = render(ResampleAlg::Bilinear, "test_builder_bilinear.vrt")?;
+
+        assert_ne!(
+            nearest, bilinear,
+            "nearest-neighbour and bilinear renders of the same coarser VRT should disagree \
+             on at least one pixel if the `-r` flag actually reached gdalbuildvrt"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn vrt_with_progress_callback() -> errors::Result<()> {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let infile = Path::new("fixtures/m_3607824_se_17_1_20160620_sub.tif");
+        let ds = Dataset::open(infile)?;
+        let outfile = env::temp_dir().join("test_progress.vrt");
+
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let calls_in_callback = Rc::clone(&calls);
+        let options = BuildVRTOptionsBuilder::new()
+            .progress(move |complete, message| {
+                calls_in_callback
+                    .borrow_mut()
+                    .push((complete, message.to_string()));
+                true
+            })
+            .build()?;
+        build_vrt(Some(&outfile), &[&ds], Some(options))?;
+
+        let calls = calls.borrow();
+        assert!(!calls.is_empty());
+        assert_eq!(calls.last().unwrap().0, 1.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn vrt_progress_callback_can_cancel() -> errors::Result<()> {
+        let infile = Path::new("fixtures/m_3607824_se_17_1_20160620_sub.tif");
+        let ds = Dataset::open(infile)?;
+        let outfile = env::temp_dir().join("test_progress_cancel.vrt");
+
+        let options = BuildVRTOptionsBuilder::new()
+            .progress(|_complete, _message| false)
+            .build()?;
+        let result = build_vrt(Some(&outfile), &[&ds], Some(options));
+
+        assert!(result.is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn vrt_xml_in_memory() -> errors::Result<()> {
+        use crate::programs::raster::build_vrt_xml;
+
+        let infile = Path::new("fixtures/m_3607824_se_17_1_20160620_sub.tif");
+        let ds = Dataset::open(infile)?;
+
+        let xml = build_vrt_xml(&[&ds], None)?;
+        assert!(xml.contains("<VRTDataset"));
+
+        Ok(())
+    }
 }
\ No newline at end of file
@@ -1,5 +1,7 @@
 use crate::vector::Geometry;
-use std::ffi::c_void;
+use gdal_sys::{CPLErr, CPLErrorNum};
+use std::cell::RefCell;
+use std::ffi::{c_void, CStr};
 use std::marker::PhantomData;
 use std::path::{Path, PathBuf};
 
@@ -133,3 +135,150 @@ impl Drop for SuppressGDALErrorLog {
         unsafe { gdal_sys::CPLPopErrorHandler() };
     }
 }
+
+/// A single message captured by [`CaptureGDALErrors`].
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct GdalLogRecord {
+    pub(crate) class: CPLErr::Type,
+    pub(crate) number: CPLErrorNum,
+    pub(crate) message: String,
+}
+
+/// `CPLErrorHandler` that forwards into the calling [`CaptureGDALErrors`]'s own buffer instead of
+/// printing.
+///
+/// The buffer to write to is recovered from `CPLGetErrorHandlerUserData`, i.e. the user data
+/// associated with the handler `CaptureGDALErrors::new` pushed via `CPLPushErrorHandlerEx`. Since
+/// every instance pushes its own buffer, nested `CaptureGDALErrors` scopes on the same thread
+/// each record into their own `Vec` rather than sharing (and clobbering) one thread-local.
+///
+/// Uses `try_borrow_mut` rather than `borrow_mut` so that a message arriving while the buffer
+/// is already borrowed is silently dropped instead of panicking across the FFI boundary.
+unsafe extern "C" fn capture_gdal_error_handler(
+    class: CPLErr::Type,
+    number: CPLErrorNum,
+    message: *const libc::c_char,
+) {
+    let user_data = gdal_sys::CPLGetErrorHandlerUserData();
+    if user_data.is_null() {
+        return;
+    }
+    let records = &*(user_data as *const RefCell<Vec<GdalLogRecord>>);
+
+    let message = if message.is_null() {
+        String::new()
+    } else {
+        CStr::from_ptr(message).to_string_lossy().into_owned()
+    };
+
+    if let Ok(mut records) = records.try_borrow_mut() {
+        records.push(GdalLogRecord {
+            class,
+            number,
+            message,
+        });
+    }
+}
+
+/// Scoped value for capturing thread-local GDAL log messages instead of letting them reach
+/// stderr, so tests can assert on a specific [`CPLErr::Type`]/[`CPLErrorNum`] pair instead of
+/// eyeballing the message text.
+///
+/// Sibling to [`SuppressGDALErrorLog`], which only silences messages; this additionally records
+/// them for later inspection via [`CaptureGDALErrors::records`] or [`CaptureGDALErrors::take`].
+pub(crate) struct CaptureGDALErrors {
+    // Boxed so the buffer's heap address (which GDAL holds onto as the handler's user data via
+    // `CPLPushErrorHandlerEx`) stays stable even if `self` moves. Each instance owns its own
+    // buffer, so nested scopes on the same thread don't clobber one another.
+    records: Box<RefCell<Vec<GdalLogRecord>>>,
+    // Make !Sync and !Send, and force use of `new`.
+    _not_send_or_sync: PhantomData<*mut c_void>,
+}
+
+impl CaptureGDALErrors {
+    pub(crate) fn new() -> Self {
+        let records = Box::new(RefCell::new(Vec::new()));
+
+        unsafe {
+            gdal_sys::CPLPushErrorHandlerEx(
+                Some(capture_gdal_error_handler),
+                records.as_ref() as *const RefCell<Vec<GdalLogRecord>> as *mut c_void,
+            );
+        }
+
+        CaptureGDALErrors {
+            records,
+            _not_send_or_sync: PhantomData,
+        }
+    }
+
+    /// Returns the messages captured so far, without clearing them.
+    pub(crate) fn records(&self) -> Vec<GdalLogRecord> {
+        self.records.borrow().clone()
+    }
+
+    /// Returns the messages captured so far, clearing them.
+    pub(crate) fn take(&self) -> Vec<GdalLogRecord> {
+        self.records.take()
+    }
+}
+
+impl Drop for CaptureGDALErrors {
+    fn drop(&mut self) {
+        unsafe { gdal_sys::CPLPopErrorHandler() };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn capture_gdal_errors_records_real_error() {
+        let capture = CaptureGDALErrors::new();
+
+        unsafe {
+            gdal_sys::CPLError(
+                CPLErr::CE_Warning,
+                42,
+                b"synthetic test warning\0".as_ptr() as *const libc::c_char,
+            );
+        }
+
+        let records = capture.take();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].class, CPLErr::CE_Warning);
+        assert_eq!(records[0].number, 42);
+        assert_eq!(records[0].message, "synthetic test warning");
+
+        // `take` clears the buffer.
+        assert!(capture.records().is_empty());
+    }
+
+    #[test]
+    fn capture_gdal_errors_nested_scopes_do_not_clobber() {
+        let outer = CaptureGDALErrors::new();
+        unsafe {
+            gdal_sys::CPLError(CPLErr::CE_Warning, 1, b"outer\0".as_ptr() as *const libc::c_char);
+        }
+
+        {
+            let inner = CaptureGDALErrors::new();
+            unsafe {
+                gdal_sys::CPLError(
+                    CPLErr::CE_Failure,
+                    2,
+                    b"inner\0".as_ptr() as *const libc::c_char,
+                );
+            }
+            let inner_records = inner.take();
+            assert_eq!(inner_records.len(), 1);
+            assert_eq!(inner_records[0].message, "inner");
+        }
+
+        // The outer scope's record must have survived the inner scope's construction and drop.
+        let outer_records = outer.take();
+        assert_eq!(outer_records.len(), 1);
+        assert_eq!(outer_records[0].message, "outer");
+    }
+}